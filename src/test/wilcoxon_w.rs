@@ -1,11 +1,13 @@
 use core::fmt::Debug;
-use std::ops::Sub;
 
 use crate::distribution::SignedRank;
 use crate::statistics::*;
 use crate::traits::abs::Abs;
 use crate::traits::zero::Zero;
+use ndarray::{ArrayView2, Axis};
+use rand::Rng;
 use statrs::distribution::ContinuousCDF;
+use statrs::StatsError;
 
 use super::StatisticalTest;
 use core::cmp::Ordering;
@@ -99,6 +101,237 @@ impl Radixable<i64> for AbsWrapper<i64> {
     }
 }
 
+#[cfg(feature = "voracious_radix_sort")]
+impl Radixable<i128> for AbsWrapper<i128> {
+    type Key = i128;
+
+    #[inline]
+    fn key(&self) -> Self::Key {
+        self.value.abs()
+    }
+}
+
+/// Widens a signed integer into a type that can represent `self - other` without overflow.
+/// Floating-point types widen to themselves.
+pub trait WideningSub: Copy {
+    /// The type deltas are accumulated in; wide enough that the subtraction cannot overflow.
+    type Wide: Abs<Output = Self::Wide> + PartialOrd + Zero + Copy + Debug;
+
+    /// Computes `self - other` in [`WideningSub::Wide`].
+    fn widening_sub(self, other: Self) -> Self::Wide;
+}
+
+macro_rules! impl_widening_sub_integer {
+    ($($narrow:ty => $wide:ty),* $(,)?) => {
+        $(
+            impl WideningSub for $narrow {
+                type Wide = $wide;
+
+                #[inline]
+                fn widening_sub(self, other: Self) -> Self::Wide {
+                    self as $wide - other as $wide
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_sub_integer!(i8 => i16, i16 => i32, i32 => i64, i64 => i128);
+
+macro_rules! impl_widening_sub_float {
+    ($($float:ty),* $(,)?) => {
+        $(
+            impl WideningSub for $float {
+                type Wide = $float;
+
+                #[inline]
+                fn widening_sub(self, other: Self) -> Self::Wide {
+                    self - other
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_sub_float!(f32, f64);
+
+/// Sample size (excluding zero differences) at or below which [`WilcoxonWTest::paired_with_sort`]
+/// computes the p-value exactly via [`exact_signed_rank_p_value`] instead of the asymptotic
+/// `SignedRank` normal approximation.
+const EXACT_SAMPLE_THRESHOLD: usize = 25;
+
+/// Computes the exact two-sided p-value of the Wilcoxon signed-rank statistic from the absolute
+/// ranks of the non-zero paired deltas, via the classic generating-function convolution.
+///
+/// The null distribution of `2·W+` is the coefficient vector of `∏ (1 + x^{2·rank_i})`, ranks
+/// doubled to keep averaged tie ranks exact. The convolution is carried out in probability space
+/// rather than raw sign-assignment counts, since the latter sums to `2^m` and overflows even
+/// `u128` once `m >= 128`.
+fn exact_signed_rank_p_value(non_zero_ranks: &[f64], observed_w_plus: f64) -> f64 {
+    let doubled_ranks: Vec<usize> = non_zero_ranks
+        .iter()
+        .map(|&rank| (2.0 * rank).round() as usize)
+        .collect();
+    let total_doubled: usize = doubled_ranks.iter().sum();
+
+    let mut probabilities = vec![0.0_f64; total_doubled + 1];
+    probabilities[0] = 1.0;
+    let mut len = 1;
+
+    for doubled_rank in doubled_ranks {
+        let new_len = len + doubled_rank;
+        let mut next = vec![0.0_f64; new_len];
+        for (value, &probability) in probabilities.iter().enumerate().take(len) {
+            if probability == 0.0 {
+                continue;
+            }
+            let half = probability * 0.5;
+            next[value] += half;
+            next[value + doubled_rank] += half;
+        }
+        probabilities = next;
+        len = new_len;
+    }
+
+    let rank_sum = total_doubled as f64 / 2.0;
+    let expected_w_plus = rank_sum / 2.0;
+    let observed_deviation = (observed_w_plus - expected_w_plus).abs();
+
+    probabilities
+        .iter()
+        .enumerate()
+        .filter(|&(doubled_w_plus, _)| {
+            let w_plus = doubled_w_plus as f64 / 2.0;
+            (w_plus - expected_w_plus).abs() >= observed_deviation - f64::EPSILON
+        })
+        .map(|(_, &probability)| probability)
+        .sum()
+}
+
+/// Draws a single sample from a zero-centered Laplace distribution with the given `scale`, via
+/// inverse-transform sampling.
+fn sample_laplace_noise<R: Rng>(rng: &mut R, scale: f64) -> f64 {
+    let u: f64 = rng.gen_range(-0.5..0.5);
+    -scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+}
+
+/// Computes the paired deltas between `x` and `y`, sorted by absolute value via `sort`, and ranks
+/// them via [`ResolveTies`]. Returns `(n, estimate, zeroes, non_zero_ranks, tie_correction)`, where
+/// `estimate` is the `(W-, W+)` rank-sum pair and `non_zero_ranks` holds the absolute rank of each
+/// non-zero delta. Shared by every `WilcoxonWTest` constructor.
+fn rank_paired_deltas<I, J, F>(x: I, y: J, sort: F) -> (usize, (f64, f64), usize, Vec<f64>, f64)
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::IntoIter: ExactSizeIterator,
+    J::IntoIter: ExactSizeIterator,
+    I::Item: Copy + Debug + WideningSub,
+    F: Fn(&mut [<I::Item as WideningSub>::Wide]),
+{
+    let x_iter = x.into_iter();
+    let y_iter = y.into_iter();
+    let x_len: usize = x_iter.len();
+    let y_len = y_iter.len();
+
+    assert_eq!(x_len, y_len, "Samples must have the same length");
+
+    let mut deltas: Vec<<I::Item as WideningSub>::Wide> =
+        x_iter.zip(y_iter).map(|(x, y)| x.widening_sub(y)).collect();
+
+    sort(&mut deltas);
+
+    let mut tie_solver = ResolveTies::new(
+        deltas.iter().copied(),
+        <<I::Item as WideningSub>::Wide as Abs>::abs,
+    );
+
+    let mut estimate = (0.0, 0.0);
+    let mut zeroes = 0;
+    let mut non_zero_ranks = Vec::new();
+
+    for (rank, delta) in &mut tie_solver {
+        if delta < <<I::Item as WideningSub>::Wide as Zero>::ZERO {
+            estimate.0 += rank;
+            non_zero_ranks.push(rank);
+        } else if delta > <<I::Item as WideningSub>::Wide as Zero>::ZERO {
+            estimate.1 += rank;
+            non_zero_ranks.push(rank);
+        } else {
+            zeroes += 1;
+        }
+    }
+
+    let tie_correction = tie_solver.tie_correction();
+
+    (x_len, estimate, zeroes, non_zero_ranks, tie_correction)
+}
+
+/// The default sort used by every constructor that neither takes a `sort` callback nor uses the
+/// radix sort of `voracious_paired`: order deltas by absolute value with the standard comparison
+/// sort.
+fn sort_by_abs<T: Abs<Output = T> + PartialOrd + Copy>(deltas: &mut [T]) {
+    deltas.sort_unstable_by(|a, b| {
+        a.abs()
+            .partial_cmp(&b.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+/// The alternative hypothesis tested against the null that `x` and `y` are drawn from the same
+/// distribution, used by [`WilcoxonWTest::paired_with_options`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alternative {
+    /// `x` and `y` differ, in either direction.
+    TwoSided,
+    /// `x` is stochastically less than `y`.
+    Less,
+    /// `x` is stochastically greater than `y`.
+    Greater,
+}
+
+/// The noise mechanism used to privatize a released statistic, as reported on
+/// [`PrivateWilcoxonWTest`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PrivacyMechanism {
+    /// Laplace mechanism, calibrated to the sensitivity of the positive-rank sum `W+` and the
+    /// chosen `epsilon`.
+    Laplace,
+}
+
+/// A differentially private release of the Wilcoxon signed-rank statistic, produced by
+/// [`WilcoxonWTest::paired_private`]. The raw paired deltas never leave this function: only the
+/// noised estimate and the p-value derived from it are reported, alongside the privacy budget
+/// spent so callers can reason about composition across multiple tests.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PrivateWilcoxonWTest {
+    noised_estimate: f64,
+    p_value: f64,
+    mechanism: PrivacyMechanism,
+    epsilon: f64,
+}
+
+impl PrivateWilcoxonWTest {
+    /// The noised release of the positive-rank sum `W+`.
+    pub fn noised_estimate(&self) -> f64 {
+        self.noised_estimate
+    }
+
+    /// The p-value derived from [`PrivateWilcoxonWTest::noised_estimate`].
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+
+    /// The mechanism used to noise the released statistic.
+    pub fn mechanism(&self) -> PrivacyMechanism {
+        self.mechanism
+    }
+
+    /// The privacy budget spent releasing this statistic.
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+}
+
 /// Implements the [Wilcoxon signed rank test](https://en.wikipedia.org/wiki/Wilcoxon_signed-rank_test).
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct WilcoxonWTest {
@@ -114,17 +347,9 @@ impl WilcoxonWTest {
         J: IntoIterator<Item = I::Item>,
         I::IntoIter: ExactSizeIterator,
         J::IntoIter: ExactSizeIterator,
-        I::Item: Copy + Debug + Sub<I::Item>,
-        <I::Item as Sub<I::Item>>::Output:
-            Abs<Output = <I::Item as Sub<I::Item>>::Output> + PartialOrd + Zero + Copy + Debug,
+        I::Item: Copy + Debug + WideningSub,
     {
-        WilcoxonWTest::paired_with_sort(x, y, |x| {
-            x.sort_unstable_by(|a, b| {
-                a.abs()
-                    .partial_cmp(&b.abs())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-        })
+        WilcoxonWTest::paired_with_sort(x, y, sort_by_abs)
     }
 
     #[cfg(feature = "voracious_radix_sort")]
@@ -134,19 +359,13 @@ impl WilcoxonWTest {
         J: IntoIterator<Item = I::Item>,
         I::IntoIter: ExactSizeIterator,
         J::IntoIter: ExactSizeIterator,
-        I::Item: Copy + Debug + Sub<I::Item>,
-        <I::Item as Sub<I::Item>>::Output: Abs<Output = <I::Item as Sub<I::Item>>::Output>
-            + PartialOrd
-            + Zero
-            + Copy
-            + Debug
-            + RadixKey
-            + Abs,
-        AbsWrapper<<I::Item as Sub<I::Item>>::Output>: Radixable<<I::Item as Sub<I::Item>>::Output>,
+        I::Item: Copy + Debug + WideningSub,
+        <I::Item as WideningSub>::Wide: RadixKey,
+        AbsWrapper<<I::Item as WideningSub>::Wide>: Radixable<<I::Item as WideningSub>::Wide>,
     {
-        WilcoxonWTest::paired_with_sort(x, y, |x: &mut [<I::Item as Sub<I::Item>>::Output]| {
+        WilcoxonWTest::paired_with_sort(x, y, |x: &mut [<I::Item as WideningSub>::Wide]| {
             // Since the AbsWrapper is a transparent wrapper, we can just cast the slice to a slice of AbsWrapper
-            let x: &mut [AbsWrapper<<I::Item as Sub<I::Item>>::Output>] =
+            let x: &mut [AbsWrapper<<I::Item as WideningSub>::Wide>] =
                 unsafe { std::mem::transmute(x) };
             x.voracious_sort();
         })
@@ -159,39 +378,126 @@ impl WilcoxonWTest {
         J: IntoIterator<Item = I::Item>,
         I::IntoIter: ExactSizeIterator,
         J::IntoIter: ExactSizeIterator,
-        I::Item: Copy + Debug + Sub<I::Item>,
-        <I::Item as Sub<I::Item>>::Output:
-            Abs<Output = <I::Item as Sub<I::Item>>::Output> + PartialOrd + Zero + Copy + Debug,
-        F: Fn(&mut [<I::Item as Sub<I::Item>>::Output]),
+        I::Item: Copy + Debug + WideningSub,
+        F: Fn(&mut [<I::Item as WideningSub>::Wide]),
     {
-        let x_iter = x.into_iter();
-        let y_iter = y.into_iter();
-        let x_len: usize = x_iter.len();
-        let y_len = y_iter.len();
-
-        assert_eq!(x_len, y_len, "Samples must have the same length");
+        WilcoxonWTest::paired_with_sort_and_options(x, y, Alternative::TwoSided, false, sort)
+    }
 
-        let mut deltas: Vec<<I::Item as Sub<I::Item>>::Output> =
-            x_iter.zip(y_iter).map(|(x, y)| x - y).collect();
+    /// Run the Wilcoxon signed rank test on samples `x` and `y` with an explicit `alternative`
+    /// hypothesis and normal-approximation continuity correction, sorting deltas by absolute
+    /// value with the default comparison sort. See
+    /// [`WilcoxonWTest::paired_with_sort_and_options`] for the full behavior.
+    pub fn paired_with_options<I, J>(
+        x: I,
+        y: J,
+        alternative: Alternative,
+        continuity_correction: bool,
+    ) -> statrs::Result<WilcoxonWTest>
+    where
+        I: IntoIterator,
+        J: IntoIterator<Item = I::Item>,
+        I::IntoIter: ExactSizeIterator,
+        J::IntoIter: ExactSizeIterator,
+        I::Item: Copy + Debug + WideningSub,
+    {
+        WilcoxonWTest::paired_with_sort_and_options(
+            x,
+            y,
+            alternative,
+            continuity_correction,
+            sort_by_abs,
+        )
+    }
 
-        sort(&mut deltas);
+    /// Run Wilcoxon signed rank test on samples `x` and `y`, with full control over the sort
+    /// routine used to order deltas by absolute value, the `alternative` hypothesis, and whether
+    /// to apply the normal-approximation continuity correction.
+    ///
+    /// `continuity_correction` only affects the asymptotic `SignedRank` CDF path; it has no effect
+    /// when the number of non-zero deltas is at or below [`EXACT_SAMPLE_THRESHOLD`], where the
+    /// p-value is exact.
+    pub fn paired_with_sort_and_options<I, J, F>(
+        x: I,
+        y: J,
+        alternative: Alternative,
+        continuity_correction: bool,
+        sort: F,
+    ) -> statrs::Result<WilcoxonWTest>
+    where
+        I: IntoIterator,
+        J: IntoIterator<Item = I::Item>,
+        I::IntoIter: ExactSizeIterator,
+        J::IntoIter: ExactSizeIterator,
+        I::Item: Copy + Debug + WideningSub,
+        F: Fn(&mut [<I::Item as WideningSub>::Wide]),
+    {
+        let (x_len, estimate, zeroes, non_zero_ranks, tie_correction) =
+            rank_paired_deltas(x, y, sort);
 
-        let mut tie_solver = ResolveTies::new(
-            deltas.iter().copied(),
-            <<I::Item as Sub<I::Item>>::Output as Abs>::abs,
-        );
+        let estimate_small = if estimate.0 < estimate.1 {
+            estimate.0
+        } else {
+            estimate.1
+        };
 
-        let mut estimate = (0.0, 0.0);
-        let mut zeroes = 0;
+        let n = x_len as f64;
+        let mean = non_zero_ranks.iter().sum::<f64>() / 2.0;
 
-        for (rank, delta) in &mut tie_solver {
-            if delta < <<I::Item as Sub<I::Item>>::Output as Zero>::ZERO {
-                estimate.0 += rank;
-            } else if delta > <<I::Item as Sub<I::Item>>::Output as Zero>::ZERO {
-                estimate.1 += rank;
+        let two_sided_p_value = if non_zero_ranks.len() <= EXACT_SAMPLE_THRESHOLD {
+            exact_signed_rank_p_value(&non_zero_ranks, estimate.1)
+        } else {
+            let corrected_estimate_small = if continuity_correction {
+                if estimate_small < mean {
+                    estimate_small + 0.5
+                } else {
+                    estimate_small - 0.5
+                }
             } else {
-                zeroes += 1;
-            }
+                estimate_small
+            };
+            let distribution = SignedRank::new(x_len, zeroes, tie_correction)?;
+            distribution.cdf(corrected_estimate_small)
+        };
+
+        let p_value = match alternative {
+            Alternative::TwoSided => two_sided_p_value,
+            Alternative::Less if estimate.1 <= estimate.0 => two_sided_p_value / 2.0,
+            Alternative::Less => 1.0 - two_sided_p_value / 2.0,
+            Alternative::Greater if estimate.0 <= estimate.1 => two_sided_p_value / 2.0,
+            Alternative::Greater => 1.0 - two_sided_p_value / 2.0,
+        };
+
+        let rank_sum = n * (n + 1.0) / 2.0;
+        let effect_size = estimate_small / rank_sum;
+
+        Ok(WilcoxonWTest {
+            effect_size,
+            estimate,
+            p_value,
+        })
+    }
+
+    /// Run the Wilcoxon signed rank test on samples `x` and `y`, always computing the p-value
+    /// exactly via [`exact_signed_rank_p_value`] instead of the `SignedRank` normal approximation.
+    /// Returns a [`StatsError`] above [`EXACT_SAMPLE_THRESHOLD`]; use [`WilcoxonWTest::paired`]
+    /// for larger samples.
+    pub fn paired_exact<I, J>(x: I, y: J) -> statrs::Result<WilcoxonWTest>
+    where
+        I: IntoIterator,
+        J: IntoIterator<Item = I::Item>,
+        I::IntoIter: ExactSizeIterator,
+        J::IntoIter: ExactSizeIterator,
+        I::Item: Copy + Debug + WideningSub,
+    {
+        let (x_len, estimate, _zeroes, non_zero_ranks, _tie_correction) =
+            rank_paired_deltas(x, y, sort_by_abs);
+
+        if non_zero_ranks.len() > EXACT_SAMPLE_THRESHOLD {
+            return Err(StatsError::ArgLte(
+                "number of non-zero paired differences",
+                EXACT_SAMPLE_THRESHOLD as f64,
+            ));
         }
 
         let estimate_small = if estimate.0 < estimate.1 {
@@ -199,8 +505,7 @@ impl WilcoxonWTest {
         } else {
             estimate.1
         };
-        let distribution = SignedRank::new(x_len, zeroes, tie_solver.tie_correction())?;
-        let p_value = distribution.cdf(estimate_small);
+        let p_value = exact_signed_rank_p_value(&non_zero_ranks, estimate.1);
 
         let n = x_len as f64;
         let rank_sum = n * (n + 1.0) / 2.0;
@@ -212,6 +517,135 @@ impl WilcoxonWTest {
             p_value,
         })
     }
+
+    /// Run the Wilcoxon signed rank test on samples `x` and `y`, estimating the p-value via
+    /// Monte-Carlo sign-permutation resampling instead of the asymptotic `SignedRank` CDF.
+    /// Returns a [`StatsError`] if `iterations` is zero.
+    pub fn paired_permutation<I, J, R>(
+        x: I,
+        y: J,
+        rng: &mut R,
+        iterations: usize,
+    ) -> statrs::Result<WilcoxonWTest>
+    where
+        I: IntoIterator,
+        J: IntoIterator<Item = I::Item>,
+        I::IntoIter: ExactSizeIterator,
+        J::IntoIter: ExactSizeIterator,
+        I::Item: Copy + Debug + WideningSub,
+        R: Rng,
+    {
+        if iterations == 0 {
+            return Err(StatsError::ArgMustBePositive("iterations"));
+        }
+
+        let (x_len, estimate, _zeroes, non_zero_ranks, _tie_correction) =
+            rank_paired_deltas(x, y, sort_by_abs);
+
+        let expected = non_zero_ranks.iter().sum::<f64>() / 2.0;
+        let observed_deviation = (estimate.1 - expected).abs();
+
+        let extreme = (0..iterations)
+            .filter(|_| {
+                let resampled_positive: f64 = non_zero_ranks
+                    .iter()
+                    .filter(|_| rng.gen::<bool>())
+                    .sum();
+                (resampled_positive - expected).abs() >= observed_deviation
+            })
+            .count();
+
+        let p_value = extreme as f64 / iterations as f64;
+
+        let estimate_small = if estimate.0 < estimate.1 {
+            estimate.0
+        } else {
+            estimate.1
+        };
+        let n = x_len as f64;
+        let rank_sum = n * (n + 1.0) / 2.0;
+        let effect_size = estimate_small / rank_sum;
+
+        Ok(WilcoxonWTest {
+            effect_size,
+            estimate,
+            p_value,
+        })
+    }
+
+    /// Run [`WilcoxonWTest::paired`] independently on every lane of `x` and `y` along `axis`.
+    ///
+    /// `axis` selects which index varies within a lane, not which index a lane is found at: for a
+    /// `(rows, cols)` array, `Axis(0)` treats each **column** as one paired sample, while `Axis(1)`
+    /// treats each **row** as one, matching `ArrayBase::lanes`.
+    pub fn paired_axis<T>(
+        x: ArrayView2<T>,
+        y: ArrayView2<T>,
+        axis: Axis,
+    ) -> Vec<statrs::Result<WilcoxonWTest>>
+    where
+        T: Copy + Debug + WideningSub,
+    {
+        assert_eq!(x.shape(), y.shape(), "Samples must have the same shape");
+
+        x.lanes(axis)
+            .into_iter()
+            .zip(y.lanes(axis))
+            .map(|(x_lane, y_lane)| {
+                WilcoxonWTest::paired(x_lane.iter().copied(), y_lane.iter().copied())
+            })
+            .collect()
+    }
+
+    /// Run a differentially private Wilcoxon signed rank test on samples `x` and `y`, releasing
+    /// only a noised `W+` and a p-value derived from it via [`PrivacyMechanism::Laplace`] noise
+    /// calibrated to `epsilon` and sensitivity `n`. Returns a [`StatsError`] if `epsilon` is not
+    /// strictly positive.
+    pub fn paired_private<I, J, R>(
+        x: I,
+        y: J,
+        rng: &mut R,
+        epsilon: f64,
+    ) -> statrs::Result<PrivateWilcoxonWTest>
+    where
+        I: IntoIterator,
+        J: IntoIterator<Item = I::Item>,
+        I::IntoIter: ExactSizeIterator,
+        J::IntoIter: ExactSizeIterator,
+        I::Item: Copy + Debug + WideningSub,
+        R: Rng,
+    {
+        if !(epsilon > 0.0) {
+            return Err(StatsError::ArgMustBePositive("epsilon"));
+        }
+
+        let (x_len, estimate, zeroes, non_zero_ranks, tie_correction) =
+            rank_paired_deltas(x, y, sort_by_abs);
+
+        let n = x_len as f64;
+        let sensitivity = n;
+        let noised_estimate = estimate.1 + sample_laplace_noise(rng, sensitivity / epsilon);
+
+        let mean = non_zero_ranks.iter().sum::<f64>() / 2.0;
+        let p_value = if non_zero_ranks.len() <= EXACT_SAMPLE_THRESHOLD {
+            exact_signed_rank_p_value(&non_zero_ranks, noised_estimate)
+        } else {
+            let noised_estimate_small = if noised_estimate <= mean {
+                noised_estimate
+            } else {
+                2.0 * mean - noised_estimate
+            };
+            let distribution = SignedRank::new(x_len, zeroes, tie_correction)?;
+            distribution.cdf(noised_estimate_small)
+        };
+
+        Ok(PrivateWilcoxonWTest {
+            noised_estimate,
+            p_value,
+            mechanism: PrivacyMechanism::Laplace,
+            epsilon,
+        })
+    }
 }
 
 impl StatisticalTest for WilcoxonWTest {
@@ -244,7 +678,7 @@ mod tests {
                         let y: Vec<$float> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
                         let test = WilcoxonWTest::paired(&x, &y).unwrap();
                         assert_eq!(test.estimate(), (33.5, 2.5));
-                        assert_eq!(test.p_value(), 0.027785782704095215);
+                        assert_eq!(test.p_value(), 0.0390625);
                         assert_eq!(test.effect_size(), 0.06944444444444445);
                     }
 
@@ -255,7 +689,7 @@ mod tests {
                         let y: Vec<$float> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
                         let test = WilcoxonWTest::voracious_paired(&x, &y).unwrap();
                         assert_eq!(test.estimate(), (33.5, 2.5));
-                        assert_eq!(test.p_value(), 0.027785782704095215);
+                        assert_eq!(test.p_value(), 0.0390625);
                         assert_eq!(test.effect_size(), 0.06944444444444445);
                     }
 
@@ -296,7 +730,7 @@ mod tests {
                         let y: Vec<$integer> = vec![17, 18, 13, 21, 18, 14, 13, 14];
                         let test = WilcoxonWTest::paired(&x, &y).unwrap();
                         assert_eq!(test.estimate(), (33.5, 2.5));
-                        assert_eq!(test.p_value(), 0.027785782704095215);
+                        assert_eq!(test.p_value(), 0.0390625);
                         assert_eq!(test.effect_size(), 0.06944444444444445);
                     }
 
@@ -307,7 +741,7 @@ mod tests {
                         let y: Vec<$integer> = vec![17, 18, 13, 21, 18, 14, 13, 14];
                         let test = WilcoxonWTest::voracious_paired(&x, &y).unwrap();
                         assert_eq!(test.estimate(), (33.5, 2.5));
-                        assert_eq!(test.p_value(), 0.027785782704095215);
+                        assert_eq!(test.p_value(), 0.0390625);
                         assert_eq!(test.effect_size(), 0.06944444444444445);
                     }
 
@@ -341,4 +775,275 @@ mod tests {
     }
 
     test_signed_integer!(i8, i16, i32, i64);
+
+    #[test]
+    fn paired_i8_extreme_values_does_not_overflow() {
+        // i8::MIN - i8::MAX underflows in i8, and i8::MIN.abs() panics; both must be handled by
+        // widening the delta before taking its absolute value.
+        let x: Vec<i8> = vec![i8::MIN, 0, i8::MAX];
+        let y: Vec<i8> = vec![i8::MAX, 0, i8::MIN];
+
+        assert!(WilcoxonWTest::paired(&x, &y).is_ok());
+    }
+
+    #[test]
+    fn paired_exact_matches_paired_below_threshold() {
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let exact = WilcoxonWTest::paired_exact(&x, &y).unwrap();
+        let default = WilcoxonWTest::paired(&x, &y).unwrap();
+
+        assert_eq!(exact.p_value(), default.p_value());
+    }
+
+    #[test]
+    fn paired_exact_errors_above_threshold() {
+        let x: Vec<f64> = (0..=EXACT_SAMPLE_THRESHOLD as i32).map(f64::from).collect();
+        let y: Vec<f64> = (0..=EXACT_SAMPLE_THRESHOLD as i32)
+            .map(|value| f64::from(value) + 1.0)
+            .collect();
+
+        assert!(WilcoxonWTest::paired_exact(&x, &y).is_err());
+    }
+
+    #[test]
+    fn paired_permutation_matches_paired_estimate() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let reference = WilcoxonWTest::paired(&x, &y).unwrap();
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+        let permutation = WilcoxonWTest::paired_permutation(&x, &y, &mut rng, 10_000).unwrap();
+
+        assert_eq!(permutation.estimate(), reference.estimate());
+        assert!(permutation.p_value() > 0.0 && permutation.p_value() < 1.0);
+    }
+
+    #[test]
+    fn paired_permutation_is_reproducible_with_same_seed() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let mut rng_a = ChaCha20Rng::seed_from_u64(7);
+        let a = WilcoxonWTest::paired_permutation(&x, &y, &mut rng_a, 1_000).unwrap();
+        let mut rng_b = ChaCha20Rng::seed_from_u64(7);
+        let b = WilcoxonWTest::paired_permutation(&x, &y, &mut rng_b, 1_000).unwrap();
+
+        assert_eq!(a.p_value(), b.p_value());
+    }
+
+    #[test]
+    fn paired_permutation_rejects_zero_iterations() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        assert!(WilcoxonWTest::paired_permutation(&x, &y, &mut rng, 0).is_err());
+    }
+
+    #[test]
+    fn paired_permutation_centers_on_non_zero_ranks_with_ties() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        // Two zero deltas (5-5 twice): the resampled mean must come from the two non-zero
+        // ranks only, not from n(n+1)/4 over all four pairs.
+        let x: Vec<f64> = vec![5.0, 5.0, 1.0, 2.0];
+        let y: Vec<f64> = vec![5.0, 5.0, 3.0, 1.0];
+
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+        let permutation =
+            WilcoxonWTest::paired_permutation(&x, &y, &mut rng, 200_000).unwrap();
+
+        assert!(permutation.p_value() > 0.99);
+    }
+
+    #[test]
+    fn paired_with_options_continuity_correction_shifts_asymptotic_p_value() {
+        // 30 non-zero deltas, above EXACT_SAMPLE_THRESHOLD, so this takes the asymptotic
+        // `SignedRank` path where continuity_correction actually has an effect.
+        let x: Vec<f64> = (1..=30).map(f64::from).collect();
+        let y: Vec<f64> = vec![0.0; 30];
+
+        let uncorrected =
+            WilcoxonWTest::paired_with_options(&x, &y, Alternative::TwoSided, false).unwrap();
+        let corrected =
+            WilcoxonWTest::paired_with_options(&x, &y, Alternative::TwoSided, true).unwrap();
+
+        assert_ne!(uncorrected.p_value(), corrected.p_value());
+    }
+
+    #[test]
+    fn paired_with_options_continuity_correction_centers_on_non_zero_ranks_with_ties() {
+        // 2 zero deltas plus 27 non-zero deltas (above EXACT_SAMPLE_THRESHOLD, so continuity
+        // correction runs against the asymptotic `SignedRank` path). The zero deltas still get
+        // ranked (tying for the lowest two rank slots) before being excluded from
+        // `non_zero_ranks`, so the non-zero ranks are 3..=29, not 1..=27: the correction's
+        // center must come from `sum(non_zero_ranks) / 2`, not `m(m+1)/4` over the non-zero
+        // count alone, matching the fix already applied to `paired_permutation`.
+        let mut x: Vec<f64> = vec![100.0, 100.0];
+        x.extend((1..=27).map(|delta: i32| 100.0 + delta as f64));
+        let mut y: Vec<f64> = vec![100.0, 100.0];
+        y.extend(std::iter::repeat(100.0).take(27));
+
+        let uncorrected =
+            WilcoxonWTest::paired_with_options(&x, &y, Alternative::TwoSided, false).unwrap();
+        let corrected =
+            WilcoxonWTest::paired_with_options(&x, &y, Alternative::TwoSided, true).unwrap();
+
+        assert_ne!(uncorrected.p_value(), corrected.p_value());
+        assert!(corrected.p_value() >= 0.0 && corrected.p_value() <= 1.0);
+    }
+
+    #[test]
+    fn paired_axis_matches_per_row_paired() {
+        use ndarray::array;
+
+        let x = array![[8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0], [
+            209.0, 200.0, 177.0, 169.0, 159.0, 169.0, 187.0, 198.0
+        ]];
+        let y = array![[8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0], [
+            151.0, 168.0, 147.0, 164.0, 166.0, 163.0, 176.0, 188.0
+        ]];
+
+        let results = WilcoxonWTest::paired_axis(x.view(), y.view(), Axis(1));
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref().unwrap().estimate(),
+            WilcoxonWTest::paired(x.row(0).iter().copied(), y.row(0).iter().copied())
+                .unwrap()
+                .estimate()
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap().estimate(),
+            WilcoxonWTest::paired(x.row(1).iter().copied(), y.row(1).iter().copied())
+                .unwrap()
+                .estimate()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Samples must have the same shape")]
+    fn paired_axis_panics_on_shape_mismatch() {
+        use ndarray::array;
+
+        let x = array![[8.0, 6.0], [209.0, 200.0]];
+        let y = array![[8.5, 9.0, 6.5]];
+
+        let _ = WilcoxonWTest::paired_axis(x.view(), y.view(), Axis(1));
+    }
+
+    #[test]
+    fn paired_with_options_two_sided_matches_paired() {
+        let x: Vec<f64> = vec![209.0, 200.0, 177.0, 169.0, 159.0, 169.0, 187.0, 198.0];
+        let y: Vec<f64> = vec![151.0, 168.0, 147.0, 164.0, 166.0, 163.0, 176.0, 188.0];
+
+        let default = WilcoxonWTest::paired(&x, &y).unwrap();
+        let options = WilcoxonWTest::paired_with_options(&x, &y, Alternative::TwoSided, false)
+            .unwrap();
+
+        assert_eq!(default.p_value(), options.p_value());
+    }
+
+    #[test]
+    fn paired_with_options_one_sided_halves_the_two_sided_p_value() {
+        // x is stochastically greater than y here, so W- (estimate.0) is the small tail.
+        let x: Vec<f64> = vec![209.0, 200.0, 177.0, 169.0, 159.0, 169.0, 187.0, 198.0];
+        let y: Vec<f64> = vec![151.0, 168.0, 147.0, 164.0, 166.0, 163.0, 176.0, 188.0];
+
+        let two_sided =
+            WilcoxonWTest::paired_with_options(&x, &y, Alternative::TwoSided, false).unwrap();
+        let greater =
+            WilcoxonWTest::paired_with_options(&x, &y, Alternative::Greater, false).unwrap();
+        let less = WilcoxonWTest::paired_with_options(&x, &y, Alternative::Less, false).unwrap();
+
+        assert_eq!(greater.p_value(), two_sided.p_value() / 2.0);
+        assert_eq!(less.p_value(), 1.0 - two_sided.p_value() / 2.0);
+    }
+
+    #[test]
+    fn paired_private_reports_epsilon_and_mechanism() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let test = WilcoxonWTest::paired_private(&x, &y, &mut rng, 1.0).unwrap();
+
+        assert_eq!(test.epsilon(), 1.0);
+        assert_eq!(test.mechanism(), PrivacyMechanism::Laplace);
+        assert!(test.p_value() >= 0.0 && test.p_value() <= 1.0);
+    }
+
+    #[test]
+    fn paired_private_is_reproducible_with_same_seed() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let mut rng_a = ChaCha20Rng::seed_from_u64(3);
+        let a = WilcoxonWTest::paired_private(&x, &y, &mut rng_a, 0.5).unwrap();
+        let mut rng_b = ChaCha20Rng::seed_from_u64(3);
+        let b = WilcoxonWTest::paired_private(&x, &y, &mut rng_b, 0.5).unwrap();
+
+        assert_eq!(a.noised_estimate(), b.noised_estimate());
+        assert_eq!(a.p_value(), b.p_value());
+    }
+
+    #[test]
+    fn paired_private_reflects_noised_estimate_around_non_zero_mean() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        // 2 zero deltas plus 27 non-zero deltas (above EXACT_SAMPLE_THRESHOLD): the zero deltas
+        // still get ranked (tying for the lowest two rank slots) before being excluded from
+        // `non_zero_ranks`, so the reflection center must come from `sum(non_zero_ranks) / 2`,
+        // not `m(m+1)/4` over the non-zero count alone, for the same reason as continuity
+        // correction in `paired_with_sort_and_options`. An astronomically large epsilon keeps the
+        // Laplace noise far below `f64`'s precision at this magnitude, so `noised_estimate`
+        // exercises the reflection deterministically.
+        let mut x: Vec<f64> = vec![100.0, 100.0];
+        x.extend((1..=27).map(|delta: i32| 100.0 + delta as f64));
+        let mut y: Vec<f64> = vec![100.0, 100.0];
+        y.extend(std::iter::repeat(100.0).take(27));
+
+        let reference = WilcoxonWTest::paired(&x, &y).unwrap();
+
+        let mut rng = ChaCha20Rng::seed_from_u64(11);
+        let test = WilcoxonWTest::paired_private(&x, &y, &mut rng, 1e20).unwrap();
+
+        assert!((test.noised_estimate() - reference.estimate().1).abs() < 1e-6);
+        assert!(test.p_value() >= 0.0 && test.p_value() <= 1.0);
+        // W+ = 432 reflects around 216 to W- = 0, the statistic `paired` itself uses.
+        assert_eq!(test.p_value(), reference.p_value());
+    }
+
+    #[test]
+    fn paired_private_rejects_non_positive_epsilon() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let x: Vec<f64> = vec![8.0, 6.0, 5.5, 11.0, 8.5, 5.0, 6.0, 6.0];
+        let y: Vec<f64> = vec![8.5, 9.0, 6.5, 10.5, 9.0, 7.0, 6.5, 7.0];
+
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        assert!(WilcoxonWTest::paired_private(&x, &y, &mut rng, 0.0).is_err());
+        assert!(WilcoxonWTest::paired_private(&x, &y, &mut rng, -1.0).is_err());
+        assert!(WilcoxonWTest::paired_private(&x, &y, &mut rng, f64::NAN).is_err());
+    }
 }